@@ -1,8 +1,161 @@
 use std::f32::consts::E;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
 
-pub trait Interpolator {
+/// A value type that can be interpolated between two instances of itself.
+///
+/// `t` is the normalized interpolation parameter; implementors are expected
+/// to behave sensibly for `t` outside `[0, 1]` too (i.e. extrapolate) since
+/// callers like `LinearInterpolator` may clamp before calling in, but some
+/// (e.g. `Quaternion::interpolate`) only promise correctness on `[0, 1]`.
+pub trait Interpolable: Clone + 'static {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+/// Returns `v` as an `f32` if `V` happens to be `f32`, even though the caller
+/// only knows it as `&V: Interpolable`. Lets the handful of interpolators
+/// whose `inverse` only makes sense for a scalar range (`Linear`, `Sigmoid`,
+/// `NearestNeighbor`) implement it once, generically, instead of needing a
+/// dedicated non-generic type for `V = f32`.
+fn as_f32<V: Interpolable>(v: &V) -> Option<f32> {
+    (v as &dyn std::any::Any).downcast_ref::<f32>().copied()
+}
+
+impl Interpolable for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<A: Interpolable, B: Interpolable> Interpolable for (A, B) {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        (
+            self.0.interpolate(&other.0, t),
+            self.1.interpolate(&other.1, t),
+        )
+    }
+}
+
+/// An RGB color with componentwise interpolation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl Interpolable for Rgb {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Rgb {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+        }
+    }
+}
+
+/// A 2D vector with componentwise interpolation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Interpolable for Vec2 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vec2 {
+            x: self.x.interpolate(&other.x, t),
+            y: self.y.interpolate(&other.y, t),
+        }
+    }
+}
+
+/// A 3D vector with componentwise interpolation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Interpolable for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vec3 {
+            x: self.x.interpolate(&other.x, t),
+            y: self.y.interpolate(&other.y, t),
+            z: self.z.interpolate(&other.z, t),
+        }
+    }
+}
+
+/// A unit quaternion, interpolated via slerp (spherical linear interpolation)
+/// rather than componentwise lerp so that rotations move at constant angular
+/// velocity and stay normalized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quaternion {
+    fn normalized(self) -> Self {
+        let len = (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        Quaternion {
+            w: self.w / len,
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+        }
+    }
+}
+
+impl Interpolable for Quaternion {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let mut dot = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+        let mut other = *other;
+        if dot < 0.0 {
+            // Take the shorter arc.
+            other = Quaternion {
+                w: -other.w,
+                x: -other.x,
+                y: -other.y,
+                z: -other.z,
+            };
+            dot = -dot;
+        }
+
+        const EPSILON: f32 = 1e-6;
+        if dot > 1.0 - EPSILON {
+            // Nearly identical orientations: slerp would divide by ~0, so fall
+            // back to a normalized lerp instead.
+            let lerped = Quaternion {
+                w: self.w.interpolate(&other.w, t),
+                x: self.x.interpolate(&other.x, t),
+                y: self.y.interpolate(&other.y, t),
+                z: self.z.interpolate(&other.z, t),
+            };
+            return lerped.normalized();
+        }
+
+        let theta_0 = dot.acos();
+        let theta = theta_0 * t;
+        let sin_theta_0 = theta_0.sin();
+        let s0 = (theta_0 - theta).sin() / sin_theta_0;
+        let s1 = theta.sin() / sin_theta_0;
+        Quaternion {
+            w: self.w * s0 + other.w * s1,
+            x: self.x * s0 + other.x * s1,
+            y: self.y * s0 + other.y * s1,
+            z: self.z * s0 + other.z * s1,
+        }
+    }
+}
+
+pub trait Interpolator<V: Interpolable> {
     /// Evaluates the interpolation function (f) at x: f(x).
-    fn eval(&self, x: f32) -> f32;
+    fn eval(&self, x: f32) -> V;
 
     /// Checks whether x is greater than the domain of operation of the
     /// interpolation function. It's guaranteed that if this returns true for
@@ -10,62 +163,159 @@ pub trait Interpolator {
     fn exceeds_domain(&self, x: f32) -> bool;
 
     fn get_domain(&self) -> ClosedInterval;
+
+    /// Maps a range value back to a domain point, for monotonic
+    /// interpolators whose range is `f32`. Defaults to `None` since most
+    /// implementors (in particular any `V` other than `f32`) aren't
+    /// invertible this way.
+    fn inverse(&self, _y: f32) -> Option<f32> {
+        None
+    }
+}
+
+/// Whether a bound of an interval includes its endpoint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoundKind {
+    Inclusive,
+    Exclusive,
+}
+
+impl BoundKind {
+    /// True if `(self, other)` are a matching closed/open pair, e.g. one
+    /// piece owning `..hi]` and the next owning `(hi..`.
+    fn complements(self, other: BoundKind) -> bool {
+        self != other
+    }
 }
 
 #[derive(Clone)]
 pub struct ClosedInterval {
-    bound: (f32, f32),
+    lo: f32,
+    lo_kind: BoundKind,
+    hi: f32,
+    hi_kind: BoundKind,
     length: f32,
 }
 
 impl ClosedInterval {
+    /// Inclusive on both ends, e.g. `[lo, hi]`.
     fn new(bound: (f32, f32)) -> Self {
+        Self::with_bounds(bound.0, BoundKind::Inclusive, bound.1, BoundKind::Inclusive)
+    }
+
+    fn with_bounds(lo: f32, lo_kind: BoundKind, hi: f32, hi_kind: BoundKind) -> Self {
         Self {
-            bound,
-            length: bound.1 - bound.0,
+            lo,
+            lo_kind,
+            hi,
+            hi_kind,
+            length: hi - lo,
         }
     }
 
     fn check_bound(&self) {
-        if self.bound.0 >= self.bound.1 {
+        if self.lo >= self.hi {
             // Degenerate & empty intervals not allowed.
-            panic!("Invalid interval: {} !< {}", self.bound.0, self.bound.1);
+            panic!("Invalid interval: {} !< {}", self.lo, self.hi);
         }
     }
 
-    fn contains(&self, x: f32) -> bool {
-        x >= self.bound.0 && x <= self.bound.1
+    /// Whether `x` falls within this interval, respecting each bound's
+    /// [`BoundKind`].
+    pub fn contains(&self, x: f32) -> bool {
+        let lo_ok = match self.lo_kind {
+            BoundKind::Inclusive => x >= self.lo,
+            BoundKind::Exclusive => x > self.lo,
+        };
+        let hi_ok = match self.hi_kind {
+            BoundKind::Inclusive => x <= self.hi,
+            BoundKind::Exclusive => x < self.hi,
+        };
+        lo_ok && hi_ok
+    }
+
+    /// Resolves a `RangeBounds<f32>` (e.g. `10.0..20.0`) into a `ClosedInterval`.
+    fn try_from_range_bounds(bounds: impl RangeBounds<f32>) -> Result<Self, RangeBoundsError> {
+        let (lo, lo_kind) = match bounds.start_bound() {
+            Bound::Included(&v) => (v, BoundKind::Inclusive),
+            Bound::Excluded(&v) => (v, BoundKind::Exclusive),
+            Bound::Unbounded => return Err(RangeBoundsError::Unbounded),
+        };
+        let (hi, hi_kind) = match bounds.end_bound() {
+            Bound::Included(&v) => (v, BoundKind::Inclusive),
+            Bound::Excluded(&v) => (v, BoundKind::Exclusive),
+            Bound::Unbounded => return Err(RangeBoundsError::Unbounded),
+        };
+        if lo >= hi {
+            return Err(RangeBoundsError::Degenerate);
+        }
+        Ok(Self::with_bounds(lo, lo_kind, hi, hi_kind))
     }
 }
 
-pub struct StepInterpolator {
-    domain: ClosedInterval,
-    range: ClosedInterval,
+/// Resolves a `RangeBounds<f32>` into its raw `(start, end)` endpoints,
+/// ignoring inclusivity, for contexts (like an interpolator's range) that
+/// only need the endpoint values.
+fn resolve_range_bounds(bounds: impl RangeBounds<f32>) -> Result<(f32, f32), RangeBoundsError> {
+    let lo = match bounds.start_bound() {
+        Bound::Included(&v) | Bound::Excluded(&v) => v,
+        Bound::Unbounded => return Err(RangeBoundsError::Unbounded),
+    };
+    let hi = match bounds.end_bound() {
+        Bound::Included(&v) | Bound::Excluded(&v) => v,
+        Bound::Unbounded => return Err(RangeBoundsError::Unbounded),
+    };
+    Ok((lo, hi))
 }
 
-impl StepInterpolator {
-    pub fn new(domain: (f32, f32), range: (f32, f32)) -> Self {
-        let domain_interval = ClosedInterval::new(domain);
-        domain_interval.check_bound();
-        let range_interval = ClosedInterval::new(range);
-        Self {
-            domain: domain_interval,
-            range: range_interval,
+/// An error resolving a `RangeBounds<f32>` into concrete interpolator bounds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RangeBoundsError {
+    /// A bound was `..` / unbounded, but a concrete endpoint is required.
+    Unbounded,
+    /// The resolved domain was empty or a single point (`lo >= hi`).
+    Degenerate,
+}
+
+impl fmt::Display for RangeBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeBoundsError::Unbounded => write!(f, "bound must not be unbounded"),
+            RangeBoundsError::Degenerate => write!(f, "domain must not be empty or a single point"),
         }
     }
 }
 
-impl Interpolator for StepInterpolator {
-    fn eval(&self, x: f32) -> f32 {
-        if x <= self.domain.bound.0 {
-            self.range.bound.0
+impl std::error::Error for RangeBoundsError {}
+
+pub struct StepInterpolator<V: Interpolable> {
+    domain: ClosedInterval,
+    range: (V, V),
+}
+
+impl<V: Interpolable> StepInterpolator<V> {
+    /// Shim over `with_domain` using an inclusive-both-ends `[lo, hi]` domain.
+    pub fn new(domain: (f32, f32), range: (V, V)) -> Self {
+        Self::with_domain(ClosedInterval::new(domain), range)
+    }
+
+    pub fn with_domain(domain: ClosedInterval, range: (V, V)) -> Self {
+        domain.check_bound();
+        Self { domain, range }
+    }
+}
+
+impl<V: Interpolable> Interpolator<V> for StepInterpolator<V> {
+    fn eval(&self, x: f32) -> V {
+        if x <= self.domain.lo {
+            self.range.0.clone()
         } else {
-            self.range.bound.1
+            self.range.1.clone()
         }
     }
 
     fn exceeds_domain(&self, x: f32) -> bool {
-        x >= self.domain.bound.1
+        x >= self.domain.hi
     }
 
     fn get_domain(&self) -> ClosedInterval {
@@ -73,116 +323,302 @@ impl Interpolator for StepInterpolator {
     }
 }
 
-pub struct NearestNeighborInterpolator {
+impl StepInterpolator<f32> {
+    /// E.g. `StepInterpolator::from_ranges(10.0..20.0, 100.0..200.0)`.
+    pub fn from_ranges(
+        domain: impl RangeBounds<f32>,
+        range: impl RangeBounds<f32>,
+    ) -> Result<Self, RangeBoundsError> {
+        let domain = ClosedInterval::try_from_range_bounds(domain)?;
+        let range = resolve_range_bounds(range)?;
+        Ok(Self::with_domain(domain, range))
+    }
+}
+
+pub struct NearestNeighborInterpolator<V: Interpolable> {
     domain: ClosedInterval,
-    range: ClosedInterval,
+    range: (V, V),
     midpoint: f32,
 }
 
-impl NearestNeighborInterpolator {
-    pub fn new(domain: (f32, f32), range: (f32, f32)) -> Self {
-        let domain_interval = ClosedInterval::new(domain);
-        domain_interval.check_bound();
-        let range_interval = ClosedInterval::new(range);
-        let midpoint = (domain_interval.bound.1 - domain_interval.bound.0) / 2.0
-            + domain_interval.bound.0;
+impl<V: Interpolable> NearestNeighborInterpolator<V> {
+    /// Shim over `with_domain` using an inclusive-both-ends `[lo, hi]` domain.
+    pub fn new(domain: (f32, f32), range: (V, V)) -> Self {
+        Self::with_domain(ClosedInterval::new(domain), range)
+    }
+
+    pub fn with_domain(domain: ClosedInterval, range: (V, V)) -> Self {
+        domain.check_bound();
+        let midpoint = (domain.hi - domain.lo) / 2.0 + domain.lo;
         Self {
-            domain: domain_interval,
-            range: range_interval,
+            domain,
+            range,
             midpoint,
         }
     }
 }
 
-impl Interpolator for NearestNeighborInterpolator {
-    fn eval(&self, x: f32) -> f32 {
+impl<V: Interpolable> Interpolator<V> for NearestNeighborInterpolator<V> {
+    fn eval(&self, x: f32) -> V {
         if x <= self.midpoint {
-            self.range.bound.0
+            self.range.0.clone()
         } else {
-            self.range.bound.1
+            self.range.1.clone()
         }
     }
 
     fn exceeds_domain(&self, x: f32) -> bool {
-        x >= self.domain.bound.1
+        x >= self.domain.hi
     }
 
     fn get_domain(&self) -> ClosedInterval {
         self.domain.clone()
     }
+
+    /// Maps a range value back to a domain point. Since nearest-neighbor is
+    /// a step function, not injective, this returns a canonical boundary
+    /// point on the matching side rather than a unique preimage. Only
+    /// meaningful when `V` is `f32`; any other range type keeps the trait's
+    /// default of `None`.
+    fn inverse(&self, y: f32) -> Option<f32> {
+        let lo = as_f32(&self.range.0)?;
+        let hi = as_f32(&self.range.1)?;
+        if y == lo {
+            Some(self.domain.lo)
+        } else if y == hi {
+            Some(self.domain.hi)
+        } else {
+            None
+        }
+    }
+}
+
+impl NearestNeighborInterpolator<f32> {
+    /// E.g. `NearestNeighborInterpolator::from_ranges(10.0..20.0, 100.0..200.0)`.
+    pub fn from_ranges(
+        domain: impl RangeBounds<f32>,
+        range: impl RangeBounds<f32>,
+    ) -> Result<Self, RangeBoundsError> {
+        let domain = ClosedInterval::try_from_range_bounds(domain)?;
+        let range = resolve_range_bounds(range)?;
+        Ok(Self::with_domain(domain, range))
+    }
 }
 
-pub struct LinearInterpolator {
+pub struct LinearInterpolator<V: Interpolable> {
     domain: ClosedInterval,
-    range: ClosedInterval,
-    slope: f32,
+    range: (V, V),
 }
 
-impl LinearInterpolator {
-    pub fn new(domain: (f32, f32), range: (f32, f32)) -> Self {
-        let domain_interval = ClosedInterval::new(domain);
-        let range_interval = ClosedInterval::new(range);
-        let slope = range_interval.length / domain_interval.length;
-        Self {
-            domain: domain_interval,
-            range: range_interval,
-            slope,
+impl<V: Interpolable> LinearInterpolator<V> {
+    /// Shim over `with_domain` using an inclusive-both-ends `[lo, hi]` domain.
+    pub fn new(domain: (f32, f32), range: (V, V)) -> Self {
+        Self::with_domain(ClosedInterval::new(domain), range)
+    }
+
+    pub fn with_domain(domain: ClosedInterval, range: (V, V)) -> Self {
+        Self { domain, range }
+    }
+}
+
+impl<V: Interpolable> Interpolator<V> for LinearInterpolator<V> {
+    fn eval(&self, x: f32) -> V {
+        let t = ((x - self.domain.lo) / self.domain.length)
+            .max(0.0)
+            .min(1.0);
+        self.range.0.interpolate(&self.range.1, t)
+    }
+
+    fn exceeds_domain(&self, x: f32) -> bool {
+        x >= self.domain.hi
+    }
+
+    fn get_domain(&self) -> ClosedInterval {
+        self.domain.clone()
+    }
+
+    /// Maps a range value back to a domain point: `x = (y - range.lo) /
+    /// slope + domain.lo`. Only meaningful when `V` is `f32`; any other
+    /// range type keeps the trait's default of `None`.
+    fn inverse(&self, y: f32) -> Option<f32> {
+        let lo = as_f32(&self.range.0)?;
+        let hi = as_f32(&self.range.1)?;
+        let slope = (hi - lo) / self.domain.length;
+        if slope == 0.0 {
+            return None;
         }
+        Some((y - lo) / slope + self.domain.lo)
     }
 }
 
-impl Interpolator for LinearInterpolator {
-    fn eval(&self, x: f32) -> f32 {
-        if x <= self.domain.bound.0 {
-            return self.range.bound.0;
-        } else if  x>= self.domain.bound.1 {
-            return self.range.bound.1;
+impl LinearInterpolator<f32> {
+    /// E.g. `LinearInterpolator::from_ranges(10.0..20.0, 100.0..200.0)`.
+    pub fn from_ranges(
+        domain: impl RangeBounds<f32>,
+        range: impl RangeBounds<f32>,
+    ) -> Result<Self, RangeBoundsError> {
+        let domain = ClosedInterval::try_from_range_bounds(domain)?;
+        let range = resolve_range_bounds(range)?;
+        Ok(Self::with_domain(domain, range))
+    }
+}
+
+pub struct SigmoidInterpolator<V: Interpolable> {
+    domain: ClosedInterval,
+    range: (V, V),
+}
+
+impl<V: Interpolable> SigmoidInterpolator<V> {
+    /// Shim over `with_domain` using an inclusive-both-ends `[lo, hi]` domain.
+    pub fn new(domain: (f32, f32), range: (V, V)) -> Self {
+        Self::with_domain(ClosedInterval::new(domain), range)
+    }
+
+    pub fn with_domain(domain: ClosedInterval, range: (V, V)) -> Self {
+        domain.check_bound();
+        Self { domain, range }
+    }
+}
+
+impl<V: Interpolable> Interpolator<V> for SigmoidInterpolator<V> {
+    fn eval(&self, x: f32) -> V {
+        if x <= self.domain.lo {
+            return self.range.0.clone();
+        } else if x >= self.domain.hi {
+            return self.range.1.clone();
         }
-        (x - self.domain.bound.0) * self.slope + self.range.bound.0
+        fn sigmoid(x: f32) -> f32 {
+            1.0 / (1.0 + E.powf(-x))
+        }
+        let x_prime = (x - self.domain.lo) / self.domain.length * 8.0 - 4.0;
+        self.range.0.interpolate(&self.range.1, sigmoid(x_prime))
     }
 
     fn exceeds_domain(&self, x: f32) -> bool {
-        x >= self.domain.bound.1
+        x >= self.domain.hi
     }
 
     fn get_domain(&self) -> ClosedInterval {
         self.domain.clone()
     }
+
+    /// Maps a range value back to a domain point: the logit of the
+    /// normalized `y`, rescaled back through the forward `* 8 - 4` mapping.
+    /// Only meaningful when `V` is `f32`; any other range type keeps the
+    /// trait's default of `None`.
+    fn inverse(&self, y: f32) -> Option<f32> {
+        let lo = as_f32(&self.range.0)?;
+        let hi = as_f32(&self.range.1)?;
+        let range_length = hi - lo;
+        if range_length == 0.0 {
+            return None;
+        }
+        let normalized = (y - lo) / range_length;
+        if normalized <= 0.0 || normalized >= 1.0 {
+            return None;
+        }
+        let logit = (normalized / (1.0 - normalized)).ln();
+        Some((logit + 4.0) / 8.0 * self.domain.length + self.domain.lo)
+    }
 }
 
-pub struct SigmoidInterpolator {
+impl SigmoidInterpolator<f32> {
+    /// E.g. `SigmoidInterpolator::from_ranges(10.0..20.0, 100.0..200.0)`.
+    pub fn from_ranges(
+        domain: impl RangeBounds<f32>,
+        range: impl RangeBounds<f32>,
+    ) -> Result<Self, RangeBoundsError> {
+        let domain = ClosedInterval::try_from_range_bounds(domain)?;
+        let range = resolve_range_bounds(range)?;
+        Ok(Self::with_domain(domain, range))
+    }
+}
+
+/// A piecewise cubic Hermite spline through a list of `(x, y)` knots, with
+/// Catmull-Rom tangents so callers just supply points. Unlike the other
+/// interpolators this one isn't generic over `Interpolable`: the Hermite
+/// blend needs to scale and sum tangents, not just lerp between two values.
+pub struct CubicSplineInterpolator {
     domain: ClosedInterval,
-    range: ClosedInterval,
+    knots: Vec<(f32, f32)>,
+    tangents: Vec<f32>,
 }
 
-impl SigmoidInterpolator {
-    pub fn new(domain: (f32, f32), range: (f32, f32)) -> Self {
-        let domain_interval = ClosedInterval::new(domain);
-        domain_interval.check_bound();
-        let range_interval = ClosedInterval::new(range);
+impl CubicSplineInterpolator {
+    /// `knots` must be sorted by strictly increasing `x` and have at least
+    /// two entries.
+    pub fn new(knots: Vec<(f32, f32)>) -> Self {
+        if knots.len() < 2 {
+            panic!("Need at least two knots.");
+        }
+        for w in knots.windows(2) {
+            if w[0].0 >= w[1].0 {
+                panic!("Knots must be sorted by strictly increasing x.");
+            }
+        }
+        let tangents = Self::catmull_rom_tangents(&knots);
+        let domain = ClosedInterval::new((knots.first().unwrap().0, knots.last().unwrap().0));
         Self {
-            domain: domain_interval,
-            range: range_interval,
+            domain,
+            knots,
+            tangents,
+        }
+    }
+
+    /// `m_i = (y_{i+1} - y_{i-1}) / (x_{i+1} - x_{i-1})`, with one-sided
+    /// differences at the ends.
+    fn catmull_rom_tangents(knots: &[(f32, f32)]) -> Vec<f32> {
+        let n = knots.len();
+        let mut tangents = Vec::with_capacity(n);
+        for i in 0..n {
+            let m = if i == 0 {
+                (knots[1].1 - knots[0].1) / (knots[1].0 - knots[0].0)
+            } else if i == n - 1 {
+                (knots[i].1 - knots[i - 1].1) / (knots[i].0 - knots[i - 1].0)
+            } else {
+                (knots[i + 1].1 - knots[i - 1].1) / (knots[i + 1].0 - knots[i - 1].0)
+            };
+            tangents.push(m);
+        }
+        tangents
+    }
+
+    /// The index `i` such that `x` falls in `[knots[i].0, knots[i+1].0]`.
+    fn segment_index(&self, x: f32) -> usize {
+        let idx = self
+            .knots
+            .partition_point(|&(knot_x, _)| knot_x <= x);
+        if idx == 0 {
+            0
+        } else {
+            (idx - 1).min(self.knots.len() - 2)
         }
     }
 }
 
-impl Interpolator for SigmoidInterpolator {
+impl Interpolator<f32> for CubicSplineInterpolator {
     fn eval(&self, x: f32) -> f32 {
-        if x <= self.domain.bound.0 {
-            return self.range.bound.0;
-        } else if  x>= self.domain.bound.1 {
-            return self.range.bound.1;
+        if x <= self.domain.lo {
+            return self.knots.first().unwrap().1;
+        } else if x >= self.domain.hi {
+            return self.knots.last().unwrap().1;
         }
-        fn sigmoid(x: f32) -> f32 {
-            1.0 / (1.0 + E.powf(-x))
-        }
-        let x_prime = (x - self.domain.bound.0)/self.domain.length * 8.0 - 4.0;
-        sigmoid(x_prime) * self.range.length + self.range.bound.0
+        let i = self.segment_index(x);
+        let (x0, y0) = self.knots[i];
+        let (x1, y1) = self.knots[i + 1];
+        let dx = x1 - x0;
+        let t = (x - x0) / dx;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+        h00 * y0 + h10 * dx * self.tangents[i] + h01 * y1 + h11 * dx * self.tangents[i + 1]
     }
 
     fn exceeds_domain(&self, x: f32) -> bool {
-        x >= self.domain.bound.1
+        x >= self.domain.hi
     }
 
     fn get_domain(&self) -> ClosedInterval {
@@ -190,63 +626,98 @@ impl Interpolator for SigmoidInterpolator {
     }
 }
 
-pub struct PiecewiseInterpolator {
+pub struct PiecewiseInterpolator<V: Interpolable> {
     /// Computed via union of all interpolator domains.
     domain: ClosedInterval,
-    interpolators: Vec<Box<Interpolator>>,
+    interpolators: Vec<Box<Interpolator<V>>>,
+    /// Domain of each interpolator, same order, kept sorted and contiguous
+    /// so `eval` can binary search instead of scanning. Keeping the full
+    /// `ClosedInterval` (not just the raw `(f32, f32)` endpoints) preserves
+    /// each bound's `BoundKind`, which is what lets a shared boundary
+    /// resolve to the one piece that actually owns it.
+    segments: Vec<ClosedInterval>,
 }
 
-impl PiecewiseInterpolator {
-    pub fn new(interpolators: Vec<Box<Interpolator>>) -> Self {
+impl<V: Interpolable> PiecewiseInterpolator<V> {
+    pub fn new(interpolators: Vec<Box<Interpolator<V>>>) -> Self {
         if interpolators.len() == 0 {
             panic!("Need at least one interpolator.");
         }
 
-        let mut expected_left_bound = None;
+        let mut expected_left_bound: Option<(f32, BoundKind)> = None;
+        let mut segments = Vec::with_capacity(interpolators.len());
         for interp in interpolators.iter() {
+            let d = interp.get_domain();
             match expected_left_bound {
-                None => {},
-                Some(assert_x0) => {
-                    if assert_x0 != interp.get_domain().bound.0 {
+                None => {}
+                Some((prev_hi, prev_hi_kind)) => {
+                    if prev_hi != d.lo || !prev_hi_kind.complements(d.lo_kind) {
                         panic!("Combined domains are not closed.")
                     }
                 }
             }
-            expected_left_bound = Some(interp.get_domain().bound.1);
+            expected_left_bound = Some((d.hi, d.hi_kind));
+            segments.push(d);
         }
 
         // Safe unwraps since we asserted above that there's at least one item.
-        let domain = ClosedInterval::new(
-            (interpolators.first().unwrap().get_domain().bound.0,
-             interpolators.last().unwrap().get_domain().bound.1)
+        let first_domain = interpolators.first().unwrap().get_domain();
+        let last_domain = interpolators.last().unwrap().get_domain();
+        let domain = ClosedInterval::with_bounds(
+            first_domain.lo,
+            first_domain.lo_kind,
+            last_domain.hi,
+            last_domain.hi_kind,
         );
 
         Self {
             domain,
             interpolators,
+            segments,
+        }
+    }
+
+    /// The index of the last segment whose start is `< x`, or `== x` with an
+    /// inclusive start, so a shared boundary resolves to whichever side's
+    /// `BoundKind` actually owns it.
+    fn segment_for(&self, x: f32) -> usize {
+        let idx = self.segments.partition_point(|seg| {
+            seg.lo < x || (seg.lo == x && seg.lo_kind == BoundKind::Inclusive)
+        });
+        if idx == 0 {
+            0
+        } else {
+            idx - 1
         }
     }
+
+    /// Iterates over the `(start, end)` bounds backing each piece, in order.
+    pub fn iter_segments(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.segments.iter().map(|seg| (seg.lo, seg.hi))
+    }
 }
 
-impl Interpolator for PiecewiseInterpolator {
-    fn eval(&self, x: f32) -> f32 {
-        if x <= self.domain.bound.0 {
+impl<V: Interpolable> Interpolator<V> for PiecewiseInterpolator<V> {
+    fn eval(&self, x: f32) -> V {
+        if x <= self.domain.lo {
             return self.interpolators.first().unwrap().eval(x);
-        } else if  x >= self.domain.bound.1 {
+        } else if x >= self.domain.hi {
             return self.interpolators.last().unwrap().eval(x);
         }
-        println!("x: {:?}", x);
-        for interp in self.interpolators.iter() {
-            if interp.get_domain().contains(x) {
-                return interp.eval(x);
-            }
+        let idx = self.segment_for(x);
+        if self.segments[idx].contains(x) {
+            self.interpolators[idx].eval(x)
+        } else {
+            // `new` rejects any construction whose segments aren't
+            // contiguous with complementary bound kinds at every shared
+            // point, so every `x` within `self.domain` must fall inside
+            // exactly one segment.
+            unreachable!("x is within the combined domain but matches no segment")
         }
-        // Impossible.
-        panic!("No interpolator domain contained x.");
     }
 
     fn exceeds_domain(&self, x: f32) -> bool {
-        x >= self.domain.bound.1
+        x >= self.domain.hi
     }
 
     fn get_domain(&self) -> ClosedInterval {
@@ -258,6 +729,163 @@ impl Interpolator for PiecewiseInterpolator {
 mod tests {
     use super::Interpolator;
 
+    #[test]
+    fn rgb_interpolates_componentwise() {
+        use super::{Interpolable, Rgb};
+        let a = Rgb {
+            r: 0.0,
+            g: 10.0,
+            b: 255.0,
+        };
+        let b = Rgb {
+            r: 100.0,
+            g: 10.0,
+            b: 0.0,
+        };
+        assert_eq!(
+            a.interpolate(&b, 0.5),
+            Rgb {
+                r: 50.0,
+                g: 10.0,
+                b: 127.5,
+            }
+        );
+        assert_eq!(a.interpolate(&b, 0.0), a);
+        assert_eq!(a.interpolate(&b, 1.0), b);
+    }
+
+    #[test]
+    fn vec2_interpolates_componentwise() {
+        use super::{Interpolable, Vec2};
+        let a = Vec2 { x: 0.0, y: 10.0 };
+        let b = Vec2 { x: 10.0, y: 0.0 };
+        assert_eq!(a.interpolate(&b, 0.25), Vec2 { x: 2.5, y: 7.5 });
+    }
+
+    #[test]
+    fn vec3_interpolates_componentwise() {
+        use super::{Interpolable, Vec3};
+        let a = Vec3 {
+            x: 0.0,
+            y: 10.0,
+            z: -10.0,
+        };
+        let b = Vec3 {
+            x: 10.0,
+            y: 0.0,
+            z: 10.0,
+        };
+        assert_eq!(
+            a.interpolate(&b, 0.5),
+            Vec3 {
+                x: 5.0,
+                y: 5.0,
+                z: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn tuple_interpolates_each_element_independently() {
+        use super::Interpolable;
+        let a = (0.0, 10.0);
+        let b = (10.0, 0.0);
+        assert_eq!(a.interpolate(&b, 0.5), (5.0, 5.0));
+    }
+
+    #[test]
+    fn quaternion_slerp_generic_case() {
+        use super::{Interpolable, Quaternion};
+        // `w = cos(theta/2)`, so `b` (w=0) is a half turn (180 degrees)
+        // about Z; halfway through the slerp should land at the quarter
+        // turn (90 degrees) mark, whose `w` is `cos(90/2) = cos(45deg)`.
+        let a = Quaternion {
+            w: 1.0,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let quarter_turn_w = (std::f32::consts::FRAC_PI_4).cos();
+        let b = Quaternion {
+            w: 0.0,
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        let mid = a.interpolate(&b, 0.5);
+        let expected = Quaternion {
+            w: quarter_turn_w,
+            x: 0.0,
+            y: 0.0,
+            z: quarter_turn_w,
+        };
+        assert!((mid.w - expected.w).abs() < 1e-6);
+        assert!((mid.x - expected.x).abs() < 1e-6);
+        assert!((mid.y - expected.y).abs() < 1e-6);
+        assert!((mid.z - expected.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn quaternion_slerp_takes_shortest_arc() {
+        use super::{Interpolable, Quaternion};
+        // `b` and `-b` represent the same rotation, but `dot(a, -b) < 0`
+        // means slerping towards `-b` directly would take the long way
+        // around; `interpolate` should flip its sign first and end up
+        // identical to slerping towards `b`.
+        let a = Quaternion {
+            w: 1.0,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        // `w = cos(theta/2)`, so this is a quarter turn (90 degrees) about Y.
+        let quarter_turn = (std::f32::consts::FRAC_PI_4).cos();
+        let b = Quaternion {
+            w: quarter_turn,
+            x: 0.0,
+            y: quarter_turn,
+            z: 0.0,
+        };
+        let neg_b = Quaternion {
+            w: -b.w,
+            x: -b.x,
+            y: -b.y,
+            z: -b.z,
+        };
+        assert!(a.w * neg_b.w + a.x * neg_b.x + a.y * neg_b.y + a.z * neg_b.z < 0.0);
+
+        let via_b = a.interpolate(&b, 0.5);
+        let via_neg_b = a.interpolate(&neg_b, 0.5);
+        assert!((via_b.w - via_neg_b.w).abs() < 1e-6);
+        assert!((via_b.x - via_neg_b.x).abs() < 1e-6);
+        assert!((via_b.y - via_neg_b.y).abs() < 1e-6);
+        assert!((via_b.z - via_neg_b.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn quaternion_slerp_near_identical_orientations_falls_back_to_lerp() {
+        use super::{Interpolable, Quaternion};
+        // `dot` is so close to 1 that the slerp formula would divide by
+        // ~0; the fallback branch should still return a normalized
+        // quaternion close to both inputs.
+        let a = Quaternion {
+            w: 1.0,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let b = Quaternion {
+            w: 0.9999999,
+            x: 0.0000005,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mid = a.interpolate(&b, 0.5);
+        let len_sq = mid.w * mid.w + mid.x * mid.x + mid.y * mid.y + mid.z * mid.z;
+        assert!((len_sq - 1.0).abs() < 1e-5);
+        assert!((mid.w - 1.0).abs() < 1e-5);
+    }
+
     #[test]
     fn step() {
         use super::StepInterpolator;
@@ -272,6 +900,14 @@ mod tests {
         assert_eq!(si.exceeds_domain(21.0), true);
     }
 
+    #[test]
+    fn step_from_ranges() {
+        use super::StepInterpolator;
+        let si = StepInterpolator::from_ranges(10.0..20.0, 100.0..200.0).unwrap();
+        assert_eq!(si.eval(9.0), 100.0);
+        assert_eq!(si.eval(11.0), 200.0);
+    }
+
     #[test]
     fn nearest_neighbor() {
         use super::NearestNeighborInterpolator;
@@ -288,6 +924,10 @@ mod tests {
         assert_eq!(nni.exceeds_domain(15.0), false);
         assert_eq!(nni.exceeds_domain(21.0), true);
 
+        assert_eq!(nni.inverse(100.0), Some(10.0));
+        assert_eq!(nni.inverse(200.0), Some(20.0));
+        assert_eq!(nni.inverse(150.0), None);
+
         let nni = NearestNeighborInterpolator::new((10.0, 20.0), (-100.0, -200.0));
         assert_eq!(nni.eval(9.0), -100.0);
         assert_eq!(nni.eval(10.0), -100.0);
@@ -311,6 +951,9 @@ mod tests {
         assert_eq!(li.exceeds_domain(15.0), false);
         assert_eq!(li.exceeds_domain(21.0), true);
 
+        assert_eq!(li.inverse(125.0), Some(12.5));
+        assert_eq!(li.inverse(150.0), Some(15.0));
+
         let li = LinearInterpolator::new((10.0, 20.0), (-100.0, -200.0));
         assert_eq!(li.eval(9.0), -100.0);
         assert_eq!(li.eval(10.0), -100.0);
@@ -320,6 +963,22 @@ mod tests {
         assert_eq!(li.eval(21.0), -200.0);
     }
 
+    #[test]
+    fn linear_from_ranges() {
+        use super::{LinearInterpolator, RangeBoundsError};
+        let li = LinearInterpolator::from_ranges(10.0..20.0, 100.0..200.0).unwrap();
+        assert_eq!(li.eval(15.0), 150.0);
+
+        assert!(matches!(
+            LinearInterpolator::from_ranges(10.0.., 100.0..200.0),
+            Err(RangeBoundsError::Unbounded)
+        ));
+        assert!(matches!(
+            LinearInterpolator::from_ranges(20.0..10.0, 100.0..200.0),
+            Err(RangeBoundsError::Degenerate)
+        ));
+    }
+
     #[test]
     fn sigmoid() {
         use super::SigmoidInterpolator;
@@ -337,6 +996,10 @@ mod tests {
         assert_eq!(si.exceeds_domain(15.0), false);
         assert_eq!(si.exceeds_domain(21.0), true);
 
+        assert!((si.inverse(104.74258731).unwrap() - 11.0).abs() < 1e-3);
+        assert_eq!(si.inverse(100.0), None);
+        assert_eq!(si.inverse(200.0), None);
+
         let si = SigmoidInterpolator::new((10.0, 18.0), (-100.0, -200.0));
         assert_eq!(si.eval(9.0), -100.0);
         assert_eq!(si.eval(10.0), -100.0);
@@ -347,11 +1010,52 @@ mod tests {
         assert_eq!(si.eval(18.0), -200.0);
     }
 
+    #[test]
+    fn cubic_spline_passes_through_knots() {
+        use super::CubicSplineInterpolator;
+        let cs = CubicSplineInterpolator::new(vec![(0.0, 0.0), (1.0, 4.0), (2.0, 1.0), (4.0, 9.0)]);
+        assert_eq!(cs.eval(0.0), 0.0);
+        assert_eq!(cs.eval(1.0), 4.0);
+        assert_eq!(cs.eval(2.0), 1.0);
+        assert_eq!(cs.eval(4.0), 9.0);
+
+        assert_eq!(cs.eval(-1.0), 0.0);
+        assert_eq!(cs.eval(5.0), 9.0);
+
+        assert_eq!(cs.exceeds_domain(3.0), false);
+        assert_eq!(cs.exceeds_domain(4.0), true);
+    }
+
+    #[test]
+    fn cubic_spline_linear_knots_interpolate_linearly() {
+        use super::CubicSplineInterpolator;
+        // Catmull-Rom tangents reduce to the segment slope for evenly spaced
+        // colinear knots, so the spline should reproduce the line exactly.
+        let cs = CubicSplineInterpolator::new(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+        assert_eq!(cs.eval(0.5), 0.5);
+        assert_eq!(cs.eval(1.5), 1.5);
+        assert_eq!(cs.eval(2.5), 2.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Need at least two knots.")]
+    fn cubic_spline_panic_too_few_knots() {
+        use super::CubicSplineInterpolator;
+        CubicSplineInterpolator::new(vec![(0.0, 0.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Knots must be sorted by strictly increasing x.")]
+    fn cubic_spline_panic_unsorted_knots() {
+        use super::CubicSplineInterpolator;
+        CubicSplineInterpolator::new(vec![(0.0, 0.0), (0.0, 1.0)]);
+    }
+
     #[test]
     #[should_panic(expected = "Need at least one interpolator.")]
     fn piecewise_panic_nointerps() {
         use super::PiecewiseInterpolator;
-        PiecewiseInterpolator::new(vec![]);
+        PiecewiseInterpolator::<f32>::new(vec![]);
     }
 
     #[test]
@@ -365,13 +1069,31 @@ mod tests {
         ]);
     }
 
+    #[test]
+    #[should_panic(expected = "Combined domains are not closed.")]
+    fn piecewise_panic_ambiguous_boundary() {
+        use super::PiecewiseInterpolator;
+        use super::LinearInterpolator;
+        // Both pieces claim the shared endpoint 20 inclusively: ambiguous.
+        PiecewiseInterpolator::new(vec![
+            Box::new(LinearInterpolator::new((10.0, 20.0), (30.0, 40.0))),
+            Box::new(LinearInterpolator::new((20.0, 30.0), (40.0, 50.0))),
+        ]);
+    }
+
     #[test]
     fn piecewise() {
         use super::PiecewiseInterpolator;
         use super::LinearInterpolator;
         use super::NearestNeighborInterpolator;
+        use super::{BoundKind, ClosedInterval};
+        // The first piece owns `[10, 20)`, the second owns `[20, 30]`, so the
+        // shared boundary at 20 belongs to exactly one piece.
         let pi = PiecewiseInterpolator::new(vec![
-            Box::new(LinearInterpolator::new((10.0, 20.0), (30.0, 40.0))),
+            Box::new(LinearInterpolator::with_domain(
+                ClosedInterval::with_bounds(10.0, BoundKind::Inclusive, 20.0, BoundKind::Exclusive),
+                (30.0, 40.0),
+            )),
             Box::new(NearestNeighborInterpolator::new((20.0, 30.0), (40.0, 50.0))),
         ]);
 
@@ -391,4 +1113,46 @@ mod tests {
         assert_eq!(pi.eval(30.0), 50.0);
         assert_eq!(pi.eval(35.0), 50.0);
     }
+
+    #[test]
+    fn piecewise_iter_segments() {
+        use super::PiecewiseInterpolator;
+        use super::LinearInterpolator;
+        use super::NearestNeighborInterpolator;
+        use super::{BoundKind, ClosedInterval};
+        let pi = PiecewiseInterpolator::new(vec![
+            Box::new(LinearInterpolator::with_domain(
+                ClosedInterval::with_bounds(10.0, BoundKind::Inclusive, 20.0, BoundKind::Exclusive),
+                (30.0, 40.0),
+            )),
+            Box::new(NearestNeighborInterpolator::new((20.0, 30.0), (40.0, 50.0))),
+        ]);
+
+        let segments: Vec<(f32, f32)> = pi.iter_segments().collect();
+        assert_eq!(segments, vec![(10.0, 20.0), (20.0, 30.0)]);
+    }
+
+    #[test]
+    fn piecewise_shared_boundary_dispatches_to_owning_piece() {
+        use super::PiecewiseInterpolator;
+        use super::LinearInterpolator;
+        use super::{BoundKind, ClosedInterval};
+        // The first piece owns the shared boundary at 20 inclusively; the
+        // second explicitly excludes it. Binary-search dispatch must not
+        // silently hand 20 to the piece that declared it doesn't own it.
+        let pi = PiecewiseInterpolator::new(vec![
+            Box::new(LinearInterpolator::with_domain(
+                ClosedInterval::with_bounds(10.0, BoundKind::Inclusive, 20.0, BoundKind::Inclusive),
+                (30.0, 40.0),
+            )),
+            Box::new(LinearInterpolator::with_domain(
+                ClosedInterval::with_bounds(20.0, BoundKind::Exclusive, 30.0, BoundKind::Inclusive),
+                (1000.0, 2000.0),
+            )),
+        ]);
+
+        assert_eq!(pi.eval(20.0), 40.0);
+        assert_eq!(pi.eval(19.9), 39.9);
+        assert_eq!(pi.eval(20.1), 1010.00006);
+    }
 }